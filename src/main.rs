@@ -1,13 +1,209 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 #![allow(rustdoc::missing_crate_level_docs)] // it's an example
 
+use base64::Engine;
 use eframe::egui;
+use image::ImageFormat;
+use printpdf::*;
 use qrcode_generator::QrCodeEcc;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::BufWriter;
 use std::path::PathBuf;
 
+/// The kind of payload encoded into the QR code. Selecting a kind swaps the
+/// visible form fields and the string fed to the QR/preview/save pipeline.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    #[default]
+    VCard,
+    Wifi,
+    Url,
+    Geo,
+    Mail,
+}
+
+impl PayloadKind {
+    /// Human-readable label shown in the type selector combo-box.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PayloadKind::VCard => "Contact (vCard)",
+            PayloadKind::Wifi => "WiFi network",
+            PayloadKind::Url => "URL",
+            PayloadKind::Geo => "Geo location",
+            PayloadKind::Mail => "Email / SMS",
+        }
+    }
+
+    /// Every selectable kind, in display order.
+    pub fn all() -> [PayloadKind; 5] {
+        [
+            PayloadKind::VCard,
+            PayloadKind::Wifi,
+            PayloadKind::Url,
+            PayloadKind::Geo,
+            PayloadKind::Mail,
+        ]
+    }
+}
+
+/// Paper size for printable PDF export, portrait orientation.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum PageSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PageSize {
+    /// Page dimensions in millimetres.
+    pub fn dims_mm(&self) -> (f32, f32) {
+        match self {
+            PageSize::A4 => (210.0, 297.0),
+            PageSize::Letter => (215.9, 279.4),
+        }
+    }
+
+    /// Label shown in the page-size combo-box.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PageSize::A4 => "A4",
+            PageSize::Letter => "Letter",
+        }
+    }
+
+    /// Both selectable sizes, in display order.
+    pub fn all() -> [PageSize; 2] {
+        [PageSize::A4, PageSize::Letter]
+    }
+}
+
+/// WiFi join credentials encoded as a `WIFI:` payload.
+#[derive(Default)]
+pub struct WifiPayload {
+    pub ssid: String,
+    pub password: String,
+    /// Authentication type: `WPA`, `WEP`, or `nopass`.
+    pub encryption: String,
+}
+
+impl WifiPayload {
+    /// Builds a `WIFI:T:WPA;S:<ssid>;P:<pass>;;` string with the reserved
+    /// characters (`\ ; , : "`) backslash-escaped per the de-facto spec.
+    pub fn to_payload(&self) -> String {
+        let encryption = if self.encryption.is_empty() {
+            "WPA"
+        } else {
+            &self.encryption
+        };
+        // Open networks carry no password, so leave the `P:` value empty.
+        let password = if encryption == "nopass" {
+            String::new()
+        } else {
+            escape_wifi(&self.password)
+        };
+        format!(
+            "WIFI:T:{};S:{};P:{};;",
+            encryption,
+            escape_wifi(&self.ssid),
+            password,
+        )
+    }
+}
+
+/// A geographic coordinate encoded as a `geo:` URI.
+#[derive(Default)]
+pub struct GeoPayload {
+    pub latitude: String,
+    pub longitude: String,
+}
+
+impl GeoPayload {
+    /// Builds a `geo:<lat>,<lon>` URI.
+    pub fn to_payload(&self) -> String {
+        format!("geo:{},{}", self.latitude, self.longitude)
+    }
+}
+
+/// An email or SMS payload (`mailto:` / `SMSTO:`).
+#[derive(Default)]
+pub struct MailPayload {
+    /// When true, emit an `SMSTO:` payload instead of `mailto:`.
+    pub is_sms: bool,
+    pub recipient: String,
+    pub body: String,
+}
+
+impl MailPayload {
+    /// Builds a `mailto:<addr>?body=<body>` or `SMSTO:<number>:<body>` string.
+    pub fn to_payload(&self) -> String {
+        if self.is_sms {
+            if self.body.is_empty() {
+                format!("SMSTO:{}", self.recipient)
+            } else {
+                format!("SMSTO:{}:{}", self.recipient, self.body)
+            }
+        } else if self.body.is_empty() {
+            format!("mailto:{}", self.recipient)
+        } else {
+            format!("mailto:{}?body={}", self.recipient, self.body)
+        }
+    }
+}
+
+/// Detects the media type of an encoded image for `PHOTO` / data-URI emission,
+/// returning the vCard `TYPE` token and the MIME type. Defaults to JPEG when the
+/// format can't be recognised.
+fn image_media_type(bytes: &[u8]) -> (&'static str, &'static str) {
+    match image::guess_format(bytes) {
+        Ok(ImageFormat::Png) => ("PNG", "image/png"),
+        Ok(ImageFormat::Gif) => ("GIF", "image/gif"),
+        _ => ("JPEG", "image/jpeg"),
+    }
+}
+
+/// Backslash-escapes the characters reserved by the `WIFI:` payload syntax.
+fn escape_wifi(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '\\' | ';' | ',' | ':' | '"') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// vCard specification version emitted by `generate_vcard`.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum VCardVersion {
+    #[default]
+    V3,
+    V4,
+}
+
+impl VCardVersion {
+    /// The string written to the `VERSION:` property.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VCardVersion::V3 => "3.0",
+            VCardVersion::V4 => "4.0",
+        }
+    }
+}
+
+/// A single typed contact entry, e.g. a `work` phone or a `home` email.
+#[derive(Default)]
+pub struct TypedEntry {
+    /// Entry type such as `work`, `home`, `fax`, or `cell`.
+    pub kind: String,
+    pub value: String,
+}
+
 /// Represents business contact information for vCard generation
 #[derive(Default)]
 pub struct BusinessContact {
+    pub version: VCardVersion,
     pub first_name: String,
     pub last_name: String,
     pub organization: String,
@@ -18,6 +214,12 @@ pub struct BusinessContact {
     pub website: String,
     pub address: String,
     pub note: String,
+    /// Extra typed phone numbers beyond the primary phone/mobile.
+    pub phones: Vec<TypedEntry>,
+    /// Extra typed email addresses beyond the primary email.
+    pub emails: Vec<TypedEntry>,
+    /// Path to a photo to embed as a `PHOTO` property (optional).
+    pub photo_path: String,
 }
 
 impl BusinessContact {
@@ -26,7 +228,7 @@ impl BusinessContact {
         let mut vcard = String::new();
 
         vcard.push_str("BEGIN:VCARD\n");
-        vcard.push_str("VERSION:3.0\n");
+        vcard.push_str(&format!("VERSION:{}\n", self.version.as_str()));
         vcard.push_str(&format!("N:{};{};;;\n", self.last_name, self.first_name));
         vcard.push_str(&format!("FN:{} {}\n", self.first_name, self.last_name));
 
@@ -39,15 +241,27 @@ impl BusinessContact {
         }
 
         if !self.email.is_empty() {
-            vcard.push_str(&format!("EMAIL;type=WORK,INTERNET:{}\n", self.email));
+            vcard.push_str(&self.format_email("work", &self.email));
+        }
+        for entry in &self.emails {
+            if !entry.value.is_empty() {
+                let kind = if entry.kind.is_empty() { "work" } else { entry.kind.as_str() };
+                vcard.push_str(&self.format_email(kind, &entry.value));
+            }
         }
 
         if !self.phone.is_empty() {
-            vcard.push_str(&format!("TEL;type=WORK,voice:{}\n", self.phone));
+            vcard.push_str(&self.format_tel("work", &self.phone));
         }
 
         if !self.mobile.is_empty() {
-            vcard.push_str(&format!("TEL;type=CELL,voice:{}\n", self.mobile));
+            vcard.push_str(&self.format_tel("cell", &self.mobile));
+        }
+        for entry in &self.phones {
+            if !entry.value.is_empty() {
+                let kind = if entry.kind.is_empty() { "work" } else { entry.kind.as_str() };
+                vcard.push_str(&self.format_tel(kind, &entry.value));
+            }
         }
 
         if !self.website.is_empty() {
@@ -62,9 +276,56 @@ impl BusinessContact {
             vcard.push_str(&format!("NOTE:{}\n", self.note));
         }
 
+        if let Some(photo) = self.format_photo() {
+            vcard.push_str(&photo);
+        }
+
         vcard.push_str("END:VCARD");
         vcard
     }
+
+    /// Formats an `EMAIL` property line for the selected vCard version.
+    fn format_email(&self, kind: &str, value: &str) -> String {
+        match self.version {
+            VCardVersion::V3 => {
+                format!("EMAIL;type={},INTERNET:{}\n", kind.to_uppercase(), value)
+            }
+            VCardVersion::V4 => format!("EMAIL;TYPE={}:{}\n", kind, value),
+        }
+    }
+
+    /// Formats a `TEL` property line for the selected vCard version.
+    fn format_tel(&self, kind: &str, value: &str) -> String {
+        match self.version {
+            VCardVersion::V3 => {
+                format!("TEL;type={},voice:{}\n", kind.to_uppercase(), value)
+            }
+            VCardVersion::V4 => format!("TEL;TYPE={},voice:{}\n", kind, value),
+        }
+    }
+
+    /// Loads and base64-encodes the contact photo into a `PHOTO` property, or
+    /// returns `None` when no photo is set or the file cannot be read.
+    fn format_photo(&self) -> Option<String> {
+        if self.photo_path.is_empty() {
+            return None;
+        }
+
+        let bytes = match std::fs::read(&self.photo_path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                eprintln!("Error loading photo: {}", e);
+                return None;
+            }
+        };
+        let (type_token, mime) = image_media_type(&bytes);
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+
+        Some(match self.version {
+            VCardVersion::V3 => format!("PHOTO;ENCODING=b;TYPE={}:{}\n", type_token, encoded),
+            VCardVersion::V4 => format!("PHOTO:data:{};base64,{}\n", mime, encoded),
+        })
+    }
 }
 
 fn main() -> eframe::Result {
@@ -87,99 +348,483 @@ fn main() -> eframe::Result {
 
 struct BusinessCardApp {
     contact: BusinessContact,
-    vcard_text: String,
+    payload_kind: PayloadKind,
+    wifi: WifiPayload,
+    geo: GeoPayload,
+    mail: MailPayload,
+    url: String,
+    payload_text: String,
     show_copied_toast: bool,
     toast_time: f32,
     qr_code_texture: Option<egui::TextureHandle>,
     show_saved_toast: bool,
     saved_toast_time: f32,
     save_path: String,
+    logo_path: String,
+    pdf_path: String,
+    page_size: PageSize,
+    cards_per_page: usize,
+    svg_path: String,
+    fg_color: egui::Color32,
+    bg_color: egui::Color32,
+    quiet_zone: usize,
+    size_warning: Option<String>,
 }
 
 impl Default for BusinessCardApp {
     fn default() -> Self {
         Self {
             contact: BusinessContact::default(),
-            vcard_text: String::new(),
+            payload_kind: PayloadKind::default(),
+            wifi: WifiPayload::default(),
+            geo: GeoPayload::default(),
+            mail: MailPayload::default(),
+            url: String::new(),
+            payload_text: String::new(),
             show_copied_toast: false,
             toast_time: 0.0,
             qr_code_texture: None,
             show_saved_toast: false,
             saved_toast_time: 0.0,
             save_path: String::from("qrcode.png"),
+            logo_path: String::new(),
+            pdf_path: String::from("card.pdf"),
+            page_size: PageSize::default(),
+            cards_per_page: 1,
+            svg_path: String::from("qrcode.svg"),
+            fg_color: egui::Color32::BLACK,
+            bg_color: egui::Color32::WHITE,
+            quiet_zone: 4,
+            size_warning: None,
         }
     }
 }
 
 impl BusinessCardApp {
+    // Regenerate the payload string and its QR texture, surfacing a warning
+    // when the payload (e.g. a vCard with an embedded photo) is too large to
+    // encode at the current error-correction level instead of panicking.
+    fn regenerate(&mut self, ctx: &egui::Context) {
+        self.payload_text = self.generate_payload();
+        if self.payload_text.is_empty() {
+            return;
+        }
+
+        match self.generate_qr_code(&self.payload_text) {
+            Ok(color_image) => {
+                self.size_warning = None;
+                self.qr_code_texture =
+                    Some(ctx.load_texture("qr-code", color_image, Default::default()));
+            }
+            Err(_) => {
+                let level = if self.logo_path.is_empty() {
+                    "Medium"
+                } else {
+                    "High"
+                };
+                self.size_warning = Some(format!(
+                    "Payload too large to encode at the {} error-correction level \
+                     (try a smaller photo or fewer fields)",
+                    level
+                ));
+                self.qr_code_texture = None;
+            }
+        }
+    }
+
+    // Build the encoded string for the currently selected payload kind.
+    fn generate_payload(&self) -> String {
+        match self.payload_kind {
+            PayloadKind::VCard => self.contact.generate_vcard(),
+            PayloadKind::Wifi => self.wifi.to_payload(),
+            PayloadKind::Url => self.url.clone(),
+            PayloadKind::Geo => self.geo.to_payload(),
+            PayloadKind::Mail => self.mail.to_payload(),
+        }
+    }
+
     // Generate QR code image from a string and convert to ColorImage for egui
-    fn generate_qr_code(&self, text: &str) -> egui::ColorImage {
-        // Generate QR code with medium error correction
-        let qr_code = qrcode_generator::to_image_buffer(text, QrCodeEcc::Medium, 512).unwrap();
+    fn generate_qr_code(&self, text: &str) -> Result<egui::ColorImage, String> {
+        let (width, height, rgba_data) = self.render_qr_rgba(text)?;
+        Ok(egui::ColorImage::from_rgba_unmultiplied(
+            [width, height],
+            &rgba_data,
+        ))
+    }
 
-        // Get dimensions
-        let width = qr_code.width() as usize;
-        let height = qr_code.height() as usize;
+    // Selects the error-correction level: step up to High (~30% recoverable)
+    // whenever a center logo punches a hole in the matrix, else Medium.
+    fn qr_ecc(&self) -> QrCodeEcc {
+        if self.logo_path.is_empty() {
+            QrCodeEcc::Medium
+        } else {
+            QrCodeEcc::High
+        }
+    }
+
+    // Render the QR code for `text` into a ~512px RGBA buffer, applying the
+    // chosen module/background colors and quiet-zone margin and compositing the
+    // center logo from `self.logo_path` when one is set. Shared by the
+    // on-screen texture and the PNG export so the preview matches the file.
+    //
+    // Returns an error (rather than panicking) when the payload is too large to
+    // encode at the current level — `qr_ecc()` depends on `logo_path`, which is
+    // editable after generation, so the save/copy paths can re-encode at High.
+    fn render_qr_rgba(&self, text: &str) -> Result<(usize, usize, Vec<u8>), String> {
+        // Render from the raw module matrix so we control the quiet-zone margin
+        // and the module/background colors ourselves.
+        let matrix = qrcode_generator::to_matrix(text, self.qr_ecc())
+            .map_err(|e| format!("Failed to encode QR code: {}", e))?;
+        let modules = matrix.len();
+        let total_modules = modules + self.quiet_zone * 2;
+
+        const TARGET: usize = 512;
+        let scale = (TARGET / total_modules).max(1);
+        let dim = total_modules * scale;
+
+        let fg = self.fg_color;
+        let bg = self.bg_color;
+
+        let mut rgba_data = vec![0u8; dim * dim * 4];
+        for py in 0..dim {
+            let my = py / scale;
+            for px in 0..dim {
+                let mx = px / scale;
+                let dark = my >= self.quiet_zone
+                    && my < self.quiet_zone + modules
+                    && mx >= self.quiet_zone
+                    && mx < self.quiet_zone + modules
+                    && matrix[my - self.quiet_zone][mx - self.quiet_zone];
+                let color = if dark { fg } else { bg };
+                let idx = (py * dim + px) * 4;
+                rgba_data[idx] = color.r();
+                rgba_data[idx + 1] = color.g();
+                rgba_data[idx + 2] = color.b();
+                rgba_data[idx + 3] = 255;
+            }
+        }
 
-        // Convert from grayscale to RGBA
-        let mut rgba_data = Vec::with_capacity(width * height * 4);
+        if !self.logo_path.is_empty() {
+            self.composite_logo(&mut rgba_data, dim, dim);
+        }
 
-        for pixel in qr_code.pixels() {
-            // QR codes are black (0) and white (255)
-            let value = pixel[0];
+        Ok((dim, dim, rgba_data))
+    }
 
-            // Black pixels (value = 0) become black (0, 0, 0, 255)
-            // White pixels (value = 255) become white (255, 255, 255, 255)
-            rgba_data.push(value); // R
-            rgba_data.push(value); // G
-            rgba_data.push(value); // B
-            rgba_data.push(255); // A (always fully opaque)
+    // Clear a backing square at the exact center and alpha-blend the scaled
+    // logo into it, the way browser QR generators overlay a product glyph.
+    fn composite_logo(&self, rgba_data: &mut [u8], width: usize, height: usize) {
+        let logo = match image::open(&self.logo_path) {
+            Ok(img) => img.to_rgba8(),
+            Err(e) => {
+                eprintln!("Error loading logo: {}", e);
+                return;
+            }
+        };
+
+        // The logo occupies ~21% of the QR width, centered.
+        let box_size = (width as f32 * 0.21) as u32;
+        if box_size == 0 {
+            return;
+        }
+        let box_x = (width as u32 - box_size) / 2;
+        let box_y = (height as u32 - box_size) / 2;
+
+        // Clear a backing square in the configured background color so the logo
+        // sits on a clean patch consistent with the rest of the QR styling.
+        let bg = self.bg_color;
+        for y in box_y..box_y + box_size {
+            for x in box_x..box_x + box_size {
+                let idx = (y as usize * width + x as usize) * 4;
+                rgba_data[idx] = bg.r();
+                rgba_data[idx + 1] = bg.g();
+                rgba_data[idx + 2] = bg.b();
+                rgba_data[idx + 3] = 255;
+            }
         }
 
-        egui::ColorImage::from_rgba_unmultiplied([width, height], &rgba_data)
+        // Scale the logo to fit the backing square and alpha-blend it in.
+        let scaled = image::imageops::resize(
+            &logo,
+            box_size,
+            box_size,
+            image::imageops::FilterType::Lanczos3,
+        );
+        for (lx, ly, pixel) in scaled.enumerate_pixels() {
+            let [r, g, b, a] = pixel.0;
+            let alpha = a as f32 / 255.0;
+            let idx = ((box_y + ly) as usize * width + (box_x + lx) as usize) * 4;
+            rgba_data[idx] = (r as f32 * alpha + rgba_data[idx] as f32 * (1.0 - alpha)) as u8;
+            rgba_data[idx + 1] =
+                (g as f32 * alpha + rgba_data[idx + 1] as f32 * (1.0 - alpha)) as u8;
+            rgba_data[idx + 2] =
+                (b as f32 * alpha + rgba_data[idx + 2] as f32 * (1.0 - alpha)) as u8;
+            rgba_data[idx + 3] = 255;
+        }
     }
 
     // Save QR code as a PNG file
     fn save_qr_code_to_png(&self, path: &str) -> Result<(), String> {
-        if self.vcard_text.is_empty() {
+        if self.payload_text.is_empty() {
             return Err("No vCard generated yet".to_string());
         }
 
-        // Generate QR code directly using qrcode-generator
-        let qr_code =
-            qrcode_generator::to_image_buffer(&self.vcard_text, QrCodeEcc::Medium, 512).unwrap();
+        // Build the same RGBA buffer the preview uses so the file matches.
+        let (width, height, rgba_data) = self.render_qr_rgba(&self.payload_text)?;
+        let buffer = image::RgbaImage::from_raw(width as u32, height as u32, rgba_data)
+            .ok_or_else(|| "Failed to build image buffer".to_string())?;
 
         // Save image to file
         let file_path = PathBuf::from(path);
-        match qr_code.save(file_path) {
+        match buffer.save(file_path) {
             Ok(_) => Ok(()),
             Err(e) => Err(format!("Failed to save QR code: {}", e)),
         }
     }
+
+    // Export a print-ready PDF: an 85x55mm business card pairing the QR code
+    // with the contact fields, tiled N-up across the chosen page with crop
+    // marks so a user can print a full sheet of identical cards.
+    fn save_qr_code_to_pdf(&self, path: &str) -> Result<(), String> {
+        if self.payload_text.is_empty() {
+            return Err("No payload generated yet".to_string());
+        }
+
+        // Reuse the exact buffer the preview/PNG use so the card matches.
+        let (width, height, rgba_data) = self.render_qr_rgba(&self.payload_text)?;
+        let qr_image = image::RgbaImage::from_raw(width as u32, height as u32, rgba_data)
+            .ok_or_else(|| "Failed to build image buffer".to_string())?;
+        let qr_dynamic = image::DynamicImage::ImageRgba8(qr_image);
+
+        let (page_w, page_h) = self.page_size.dims_mm();
+        let (doc, page, layer) =
+            PdfDocument::new("Business Card", Mm(page_w), Mm(page_h), "Layer 1");
+        let font = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(|e| format!("Failed to load font: {}", e))?;
+
+        // Standard business-card dimensions, with a small page margin.
+        const CARD_W: f32 = 85.0;
+        const CARD_H: f32 = 55.0;
+        const MARGIN: f32 = 10.0;
+
+        // Grid that fits the page, capped by the requested cards-per-page.
+        let cols = (((page_w - MARGIN) / CARD_W).floor() as usize).max(1);
+        let rows = (((page_h - MARGIN) / CARD_H).floor() as usize).max(1);
+        let per_page = (cols * rows).min(self.cards_per_page.max(1));
+
+        let current_layer = doc.get_page(page).get_layer(layer);
+        for index in 0..per_page {
+            let col = index % cols;
+            let row = index / cols;
+            // printpdf's origin is bottom-left, so lay rows out top-down.
+            let card_x = MARGIN / 2.0 + col as f32 * CARD_W;
+            let card_y = page_h - MARGIN / 2.0 - (row as f32 + 1.0) * CARD_H;
+            self.draw_card(&current_layer, &font, &qr_dynamic, card_x, card_y, CARD_W, CARD_H);
+        }
+
+        let file = File::create(path).map_err(|e| format!("Failed to create PDF: {}", e))?;
+        doc.save(&mut BufWriter::new(file))
+            .map_err(|e| format!("Failed to save PDF: {}", e))?;
+        Ok(())
+    }
+
+    // Draw a single card at (x, y) in mm: the QR image on the left, the
+    // contact fields as text runs on the right, framed by corner crop marks.
+    fn draw_card(
+        &self,
+        layer: &PdfLayerReference,
+        font: &IndirectFontRef,
+        qr: &image::DynamicImage,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+    ) {
+        // The QR occupies a square along the left edge, inset by 5mm.
+        let qr_size = h - 10.0;
+        let dpi = 300.0;
+        let scale = qr_size / (qr.width() as f32 / dpi * 25.4);
+        let image = Image::from_dynamic_image(qr);
+        image.add_to_layer(
+            layer.clone(),
+            ImageTransform {
+                translate_x: Some(Mm(x + 5.0)),
+                translate_y: Some(Mm(y + 5.0)),
+                scale_x: Some(scale),
+                scale_y: Some(scale),
+                dpi: Some(dpi),
+                ..Default::default()
+            },
+        );
+
+        // Contact text runs to the right of the QR, top-down.
+        let text_x = x + qr_size + 8.0;
+        let mut text_y = y + h - 12.0;
+        let lines = [
+            format!("{} {}", self.contact.first_name, self.contact.last_name),
+            self.contact.title.clone(),
+            self.contact.organization.clone(),
+            self.contact.phone.clone(),
+            self.contact.email.clone(),
+            self.contact.website.clone(),
+        ];
+        for line in lines.iter().filter(|l| !l.trim().is_empty()) {
+            layer.use_text(line, 10.0, Mm(text_x), Mm(text_y), font);
+            text_y -= 6.0;
+        }
+
+        self.draw_crop_marks(layer, x, y, w, h);
+    }
+
+    // Draw short L-shaped crop marks at the four corners of a card.
+    fn draw_crop_marks(&self, layer: &PdfLayerReference, x: f32, y: f32, w: f32, h: f32) {
+        const LEN: f32 = 3.0;
+        for (cx, cy) in [(x, y), (x + w, y), (x, y + h), (x + w, y + h)] {
+            let hx = if (cx - x).abs() < f32::EPSILON {
+                cx - LEN
+            } else {
+                cx + LEN
+            };
+            let vy = if (cy - y).abs() < f32::EPSILON {
+                cy - LEN
+            } else {
+                cy + LEN
+            };
+            let horizontal = Line {
+                points: vec![
+                    (Point::new(Mm(cx), Mm(cy)), false),
+                    (Point::new(Mm(hx), Mm(cy)), false),
+                ],
+                is_closed: false,
+            };
+            let vertical = Line {
+                points: vec![
+                    (Point::new(Mm(cx), Mm(cy)), false),
+                    (Point::new(Mm(cx), Mm(vy)), false),
+                ],
+                is_closed: false,
+            };
+            layer.add_line(horizontal);
+            layer.add_line(vertical);
+        }
+    }
+
+    // Copy the rendered QR *image* (not just the vCard text) to the system
+    // clipboard as raw RGBA, reusing the buffer built in render_qr_rgba.
+    fn copy_qr_to_clipboard(&self) -> Result<(), String> {
+        if self.payload_text.is_empty() {
+            return Err("No payload generated yet".to_string());
+        }
+
+        let (width, height, rgba_data) = self.render_qr_rgba(&self.payload_text)?;
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Clipboard unavailable: {}", e))?;
+        clipboard
+            .set_image(arboard::ImageData {
+                width,
+                height,
+                bytes: Cow::Owned(rgba_data),
+            })
+            .map_err(|e| format!("Failed to copy image: {}", e))
+    }
+
+    // Export a crisp, infinitely-scalable vector QR code as SVG. Useful for
+    // print shops and large-format cards where the 512px raster is too coarse.
+    fn save_qr_code_to_svg(&self, path: &str) -> Result<(), String> {
+        if self.payload_text.is_empty() {
+            return Err("No payload generated yet".to_string());
+        }
+
+        let svg = self.render_qr_svg(&self.payload_text)?;
+        std::fs::write(path, svg).map_err(|e| format!("Failed to save SVG: {}", e))
+    }
+
+    // Build an SVG string from the module matrix so the quiet-zone margin, the
+    // module/background colors, and the center logo are applied deterministically
+    // and match the raster preview/PNG (rather than post-processing the crate's
+    // default output, which is brittle to color/markup changes).
+    fn render_qr_svg(&self, text: &str) -> Result<String, String> {
+        let matrix = qrcode_generator::to_matrix(text, self.qr_ecc())
+            .map_err(|e| format!("Failed to encode QR code: {}", e))?;
+        let modules = matrix.len();
+        let total = modules + self.quiet_zone * 2;
+
+        let fg = color_to_hex(self.fg_color);
+        let bg = color_to_hex(self.bg_color);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {total} {total}\" \
+             shape-rendering=\"crispEdges\">\n"
+        );
+        // Background covers the whole canvas, including the quiet-zone margin.
+        svg.push_str(&format!(
+            "<rect width=\"{total}\" height=\"{total}\" fill=\"{bg}\"/>\n"
+        ));
+        // One 1x1 rect per dark module, offset by the quiet-zone margin.
+        for (y, row) in matrix.iter().enumerate() {
+            for (x, &dark) in row.iter().enumerate() {
+                if dark {
+                    let mx = x + self.quiet_zone;
+                    let my = y + self.quiet_zone;
+                    svg.push_str(&format!(
+                        "<rect x=\"{mx}\" y=\"{my}\" width=\"1\" height=\"1\" fill=\"{fg}\"/>\n"
+                    ));
+                }
+            }
+        }
+        // Embed the center logo as a base64 image so the vector output matches.
+        if !self.logo_path.is_empty() {
+            if let Some(data_uri) = self.logo_data_uri() {
+                let box_size = total as f32 * 0.21;
+                let pos = (total as f32 - box_size) / 2.0;
+                svg.push_str(&format!(
+                    "<rect x=\"{pos}\" y=\"{pos}\" width=\"{box_size}\" height=\"{box_size}\" \
+                     fill=\"{bg}\"/>\n"
+                ));
+                svg.push_str(&format!(
+                    "<image x=\"{pos}\" y=\"{pos}\" width=\"{box_size}\" height=\"{box_size}\" \
+                     href=\"{data_uri}\"/>\n"
+                ));
+            }
+        }
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+
+    // Reads the center logo and encodes it as a base64 data URI for SVG embedding.
+    fn logo_data_uri(&self) -> Option<String> {
+        let bytes = match std::fs::read(&self.logo_path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                eprintln!("Error loading logo: {}", e);
+                return None;
+            }
+        };
+        let (_, mime) = image_media_type(&bytes);
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+        Some(format!("data:{};base64,{}", mime, encoded))
+    }
+}
+
+/// Formats an egui color as a `#rrggbb` hex string for SVG fill attributes.
+fn color_to_hex(color: egui::Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
 }
 
 impl eframe::App for BusinessCardApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Handle keyboard shortcuts
         if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(egui::Key::G)) {
-            // Ctrl+G to generate vCard
-            self.vcard_text = self.contact.generate_vcard();
-
-            // Generate QR code when vCard is generated
-            if !self.vcard_text.is_empty() {
-                let color_image = self.generate_qr_code(&self.vcard_text);
-
-                // Load or update texture
-                self.qr_code_texture =
-                    Some(ctx.load_texture("qr-code", color_image, Default::default()));
-            }
+            // Ctrl+G to generate the payload and its QR code
+            self.regenerate(ctx);
         }
 
         if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(egui::Key::C))
-            && !self.vcard_text.is_empty()
+            && !self.payload_text.is_empty()
         {
             // Ctrl+C to copy vCard to clipboard (when vCard exists)
-            ctx.output_mut(|o| o.copied_text = self.vcard_text.clone());
+            ctx.output_mut(|o| o.copied_text = self.payload_text.clone());
             self.show_copied_toast = true;
             self.toast_time = 0.0;
         }
@@ -187,7 +832,7 @@ impl eframe::App for BusinessCardApp {
         // Ctrl+S to save/download QR code as PNG
         if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(egui::Key::S))
             && self.qr_code_texture.is_some()
-            && !self.vcard_text.is_empty()
+            && !self.payload_text.is_empty()
         {
             println!("Saving QR code to file: {}", self.save_path);
             match self.save_qr_code_to_png(&self.save_path) {
@@ -230,67 +875,219 @@ impl eframe::App for BusinessCardApp {
             ui.group(|ui| {
                 ui.heading("Contact Information");
 
-                ui.columns(2, |columns| {
-                    // Left column
-                    columns[0].vertical(|ui| {
-                        ui.add_space(5.0);
-                        ui.label("First Name:");
-                        ui.text_edit_singleline(&mut self.contact.first_name);
+                // Payload type selector: switches which fields are shown and
+                // which string is encoded, reusing the rest of the pipeline.
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label("QR type:");
+                    egui::ComboBox::from_id_source("payload_kind")
+                        .selected_text(self.payload_kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in PayloadKind::all() {
+                                ui.selectable_value(&mut self.payload_kind, kind, kind.label());
+                            }
+                        });
+                });
+
+                match self.payload_kind {
+                    PayloadKind::VCard => {
+                        ui.columns(2, |columns| {
+                            // Left column
+                            columns[0].vertical(|ui| {
+                                ui.add_space(5.0);
+                                ui.label("First Name:");
+                                ui.text_edit_singleline(&mut self.contact.first_name);
+
+                                ui.add_space(5.0);
+                                ui.label("Last Name:");
+                                ui.text_edit_singleline(&mut self.contact.last_name);
+
+                                ui.add_space(5.0);
+                                ui.label("Organization:");
+                                ui.text_edit_singleline(&mut self.contact.organization);
+
+                                ui.add_space(5.0);
+                                ui.label("Title:");
+                                ui.text_edit_singleline(&mut self.contact.title);
+
+                                ui.add_space(5.0);
+                                ui.label("Email:");
+                                ui.text_edit_singleline(&mut self.contact.email);
+                            });
+
+                            // Right column
+                            columns[1].vertical(|ui| {
+                                ui.add_space(5.0);
+                                ui.label("Phone:");
+                                ui.text_edit_singleline(&mut self.contact.phone);
+
+                                ui.add_space(5.0);
+                                ui.label("Mobile:");
+                                ui.text_edit_singleline(&mut self.contact.mobile);
 
+                                ui.add_space(5.0);
+                                ui.label("Website:");
+                                ui.text_edit_singleline(&mut self.contact.website);
+
+                                ui.add_space(5.0);
+                                ui.label("Address:");
+                                ui.text_edit_singleline(&mut self.contact.address);
+
+                                ui.add_space(5.0);
+                                ui.label("Note:");
+                                ui.text_edit_singleline(&mut self.contact.note);
+                            });
+                        });
+
+                        // vCard version toggle.
                         ui.add_space(5.0);
-                        ui.label("Last Name:");
-                        ui.text_edit_singleline(&mut self.contact.last_name);
+                        ui.horizontal(|ui| {
+                            ui.label("vCard version:");
+                            ui.selectable_value(
+                                &mut self.contact.version,
+                                VCardVersion::V3,
+                                "3.0",
+                            );
+                            ui.selectable_value(
+                                &mut self.contact.version,
+                                VCardVersion::V4,
+                                "4.0",
+                            );
+                        });
 
+                        // Repeatable typed phone numbers.
                         ui.add_space(5.0);
-                        ui.label("Organization:");
-                        ui.text_edit_singleline(&mut self.contact.organization);
+                        ui.label("Additional phones:");
+                        let mut remove_phone = None;
+                        for (i, entry) in self.contact.phones.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                egui::ComboBox::from_id_source(("phone_kind", i))
+                                    .selected_text(if entry.kind.is_empty() {
+                                        "work"
+                                    } else {
+                                        &entry.kind
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        for kind in ["work", "home", "cell", "fax"] {
+                                            ui.selectable_value(
+                                                &mut entry.kind,
+                                                kind.to_string(),
+                                                kind,
+                                            );
+                                        }
+                                    });
+                                ui.text_edit_singleline(&mut entry.value);
+                                if ui.button("✖").clicked() {
+                                    remove_phone = Some(i);
+                                }
+                            });
+                        }
+                        if let Some(i) = remove_phone {
+                            self.contact.phones.remove(i);
+                        }
+                        if ui.button("➕ Add phone").clicked() {
+                            self.contact.phones.push(TypedEntry::default());
+                        }
 
+                        // Repeatable typed email addresses.
                         ui.add_space(5.0);
-                        ui.label("Title:");
-                        ui.text_edit_singleline(&mut self.contact.title);
+                        ui.label("Additional emails:");
+                        let mut remove_email = None;
+                        for (i, entry) in self.contact.emails.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                egui::ComboBox::from_id_source(("email_kind", i))
+                                    .selected_text(if entry.kind.is_empty() {
+                                        "work"
+                                    } else {
+                                        &entry.kind
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        for kind in ["work", "home"] {
+                                            ui.selectable_value(
+                                                &mut entry.kind,
+                                                kind.to_string(),
+                                                kind,
+                                            );
+                                        }
+                                    });
+                                ui.text_edit_singleline(&mut entry.value);
+                                if ui.button("✖").clicked() {
+                                    remove_email = Some(i);
+                                }
+                            });
+                        }
+                        if let Some(i) = remove_email {
+                            self.contact.emails.remove(i);
+                        }
+                        if ui.button("➕ Add email").clicked() {
+                            self.contact.emails.push(TypedEntry::default());
+                        }
 
+                        // Embedded contact photo.
                         ui.add_space(5.0);
-                        ui.label("Email:");
-                        ui.text_edit_singleline(&mut self.contact.email);
-                    });
+                        ui.label("Photo:");
+                        ui.text_edit_singleline(&mut self.contact.photo_path);
+                    }
+                    PayloadKind::Wifi => {
+                        ui.add_space(5.0);
+                        ui.label("Network name (SSID):");
+                        ui.text_edit_singleline(&mut self.wifi.ssid);
 
-                    // Right column
-                    columns[1].vertical(|ui| {
                         ui.add_space(5.0);
-                        ui.label("Phone:");
-                        ui.text_edit_singleline(&mut self.contact.phone);
+                        ui.label("Password:");
+                        ui.text_edit_singleline(&mut self.wifi.password);
 
                         ui.add_space(5.0);
-                        ui.label("Mobile:");
-                        ui.text_edit_singleline(&mut self.contact.mobile);
+                        ui.label("Encryption:");
+                        ui.horizontal(|ui| {
+                            for enc in ["WPA", "WEP", "nopass"] {
+                                ui.selectable_value(
+                                    &mut self.wifi.encryption,
+                                    enc.to_string(),
+                                    enc,
+                                );
+                            }
+                        });
+                    }
+                    PayloadKind::Url => {
+                        ui.add_space(5.0);
+                        ui.label("URL:");
+                        ui.text_edit_singleline(&mut self.url);
+                    }
+                    PayloadKind::Geo => {
+                        ui.add_space(5.0);
+                        ui.label("Latitude:");
+                        ui.text_edit_singleline(&mut self.geo.latitude);
 
                         ui.add_space(5.0);
-                        ui.label("Website:");
-                        ui.text_edit_singleline(&mut self.contact.website);
+                        ui.label("Longitude:");
+                        ui.text_edit_singleline(&mut self.geo.longitude);
+                    }
+                    PayloadKind::Mail => {
+                        ui.add_space(5.0);
+                        ui.horizontal(|ui| {
+                            ui.selectable_value(&mut self.mail.is_sms, false, "Email (mailto)");
+                            ui.selectable_value(&mut self.mail.is_sms, true, "SMS (SMSTO)");
+                        });
 
                         ui.add_space(5.0);
-                        ui.label("Address:");
-                        ui.text_edit_singleline(&mut self.contact.address);
+                        ui.label(if self.mail.is_sms {
+                            "Phone number:"
+                        } else {
+                            "Email address:"
+                        });
+                        ui.text_edit_singleline(&mut self.mail.recipient);
 
                         ui.add_space(5.0);
-                        ui.label("Note:");
-                        ui.text_edit_singleline(&mut self.contact.note);
-                    });
-                });
+                        ui.label("Message:");
+                        ui.text_edit_singleline(&mut self.mail.body);
+                    }
+                }
 
                 ui.add_space(10.0);
                 ui.horizontal(|ui| {
-                    if ui.button("Generate vCard (Ctrl+G)").clicked() {
-                        self.vcard_text = self.contact.generate_vcard();
-
-                        // Generate QR code when vCard is generated
-                        if !self.vcard_text.is_empty() {
-                            let color_image = self.generate_qr_code(&self.vcard_text);
-
-                            // Load or update texture
-                            self.qr_code_texture =
-                                Some(ctx.load_texture("qr-code", color_image, Default::default()));
-                        }
+                    if ui.button("Generate QR (Ctrl+G)").clicked() {
+                        self.regenerate(ctx);
                     }
                 });
             });
@@ -299,24 +1096,24 @@ impl eframe::App for BusinessCardApp {
 
             // Display vCard and future QR code
             ui.group(|ui| {
-                ui.heading("Generated vCard");
+                ui.heading("Generated Payload");
 
                 ui.columns(2, |columns| {
                     // vCard text
                     columns[0].vertical(|ui| {
                         ui.add_space(5.0);
                         ui.horizontal(|ui| {
-                            ui.label("vCard Content:");
+                            ui.label("Payload Content:");
                             if ui.button("Copy to Clipboard (Ctrl+C)").clicked()
-                                && !self.vcard_text.is_empty()
+                                && !self.payload_text.is_empty()
                             {
-                                ui.output_mut(|o| o.copied_text = self.vcard_text.clone());
+                                ui.output_mut(|o| o.copied_text = self.payload_text.clone());
                                 self.show_copied_toast = true;
                                 self.toast_time = 0.0;
                             }
                         });
                         ui.add(
-                            egui::TextEdit::multiline(&mut self.vcard_text)
+                            egui::TextEdit::multiline(&mut self.payload_text)
                                 .desired_width(f32::INFINITY)
                                 .desired_rows(10)
                                 .lock_focus(true)
@@ -329,19 +1126,52 @@ impl eframe::App for BusinessCardApp {
                         ui.add_space(5.0);
                         ui.heading("QR Code");
 
-                        if let Some(texture) = &self.qr_code_texture {
-                            // Display the QR code image
+                        // Warn when the payload exceeds the QR capacity.
+                        if let Some(warning) = &self.size_warning {
+                            ui.colored_label(egui::Color32::RED, warning);
+                        }
+
+                        if let Some(texture) = self.qr_code_texture.clone() {
+                            // Display the QR code image with a right-click
+                            // context menu that copies it to the clipboard.
                             let size = 200.0;
-                            let image = egui::Image::new(texture)
+                            let image = egui::Image::new(&texture)
                                 .fit_to_exact_size(egui::vec2(size, size))
-                                .bg_fill(egui::Color32::WHITE);
-
-                            ui.centered_and_justified(|ui| {
-                                ui.add(image);
+                                .bg_fill(egui::Color32::WHITE)
+                                .sense(egui::Sense::click());
+
+                            let response = ui
+                                .centered_and_justified(|ui| ui.add(image))
+                                .inner
+                                .on_hover_text("Right-click to copy the image");
+
+                            // Right-click context menu: copy the image.
+                            response.context_menu(|ui| {
+                                if ui.button("Copy Image").clicked() {
+                                    match self.copy_qr_to_clipboard() {
+                                        Ok(_) => {
+                                            self.show_copied_toast = true;
+                                            self.toast_time = 0.0;
+                                        }
+                                        Err(e) => eprintln!("Error copying image: {}", e),
+                                    }
+                                    ui.close_menu();
+                                }
                             });
 
                             // Add a button to save QR code
                             ui.add_space(10.0);
+                            ui.vertical_centered(|ui| {
+                                if ui.button("📋 Copy Image").clicked() {
+                                    match self.copy_qr_to_clipboard() {
+                                        Ok(_) => {
+                                            self.show_copied_toast = true;
+                                            self.toast_time = 0.0;
+                                        }
+                                        Err(e) => eprintln!("Error copying image: {}", e),
+                                    }
+                                }
+                            });
                             ui.vertical_centered(|ui| {
                                 ui.label("Save QR Code:");
                                 ui.horizontal(|ui| {
@@ -350,6 +1180,14 @@ impl eframe::App for BusinessCardApp {
                                         egui::vec2(200.0, 24.0),
                                         egui::TextEdit::singleline(&mut self.save_path),
                                     );
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label("Center logo:");
+                                    ui.add_sized(
+                                        egui::vec2(200.0, 24.0),
+                                        egui::TextEdit::singleline(&mut self.logo_path)
+                                            .hint_text("path to PNG/JPEG (optional)"),
+                                    );
 
                                     // Make the download button more noticeable
                                     let download_button =
@@ -379,6 +1217,104 @@ impl eframe::App for BusinessCardApp {
                                         }
                                     }
                                 });
+
+                                // Printable PDF export with card layout / N-up.
+                                ui.add_space(8.0);
+                                ui.horizontal(|ui| {
+                                    ui.label("PDF file:");
+                                    ui.add_sized(
+                                        egui::vec2(200.0, 24.0),
+                                        egui::TextEdit::singleline(&mut self.pdf_path),
+                                    );
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label("Page:");
+                                    egui::ComboBox::from_id_source("page_size")
+                                        .selected_text(self.page_size.label())
+                                        .show_ui(ui, |ui| {
+                                            for size in PageSize::all() {
+                                                ui.selectable_value(
+                                                    &mut self.page_size,
+                                                    size,
+                                                    size.label(),
+                                                );
+                                            }
+                                        });
+                                    ui.label("Cards/page:");
+                                    ui.add(egui::Slider::new(
+                                        &mut self.cards_per_page,
+                                        1..=10,
+                                    ));
+
+                                    let export_button = egui::Button::new("🖨 Export PDF")
+                                        .min_size(egui::vec2(120.0, 28.0))
+                                        .fill(egui::Color32::from_rgb(120, 160, 220));
+                                    let response = ui
+                                        .add(export_button)
+                                        .on_hover_text("Render a print-ready card sheet as PDF");
+
+                                    if response.clicked() {
+                                        println!("Exporting PDF to file: {}", self.pdf_path);
+                                        match self.save_qr_code_to_pdf(&self.pdf_path) {
+                                            Ok(_) => {
+                                                self.show_saved_toast = true;
+                                                self.saved_toast_time = 0.0;
+                                                println!(
+                                                    "PDF exported successfully to {}",
+                                                    self.pdf_path
+                                                );
+                                            }
+                                            Err(e) => {
+                                                eprintln!("Error exporting PDF: {}", e);
+                                            }
+                                        }
+                                    }
+                                });
+
+                                // QR styling: module/background colors, the
+                                // quiet-zone margin, and a vector SVG export.
+                                ui.add_space(8.0);
+                                ui.horizontal(|ui| {
+                                    ui.label("Module:");
+                                    ui.color_edit_button_srgba(&mut self.fg_color);
+                                    ui.label("Background:");
+                                    ui.color_edit_button_srgba(&mut self.bg_color);
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label("Quiet zone:");
+                                    ui.add(egui::Slider::new(&mut self.quiet_zone, 0..=8));
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label("SVG file:");
+                                    ui.add_sized(
+                                        egui::vec2(200.0, 24.0),
+                                        egui::TextEdit::singleline(&mut self.svg_path),
+                                    );
+
+                                    let svg_button = egui::Button::new("Export SVG")
+                                        .min_size(egui::vec2(120.0, 28.0))
+                                        .fill(egui::Color32::from_rgb(200, 170, 110));
+                                    let response = ui
+                                        .add(svg_button)
+                                        .on_hover_text("Save a scalable vector QR code");
+
+                                    if response.clicked() {
+                                        println!("Exporting SVG to file: {}", self.svg_path);
+                                        match self.save_qr_code_to_svg(&self.svg_path) {
+                                            Ok(_) => {
+                                                self.show_saved_toast = true;
+                                                self.saved_toast_time = 0.0;
+                                                println!(
+                                                    "SVG exported successfully to {}",
+                                                    self.svg_path
+                                                );
+                                            }
+                                            Err(e) => {
+                                                eprintln!("Error exporting SVG: {}", e);
+                                            }
+                                        }
+                                    }
+                                });
                             });
                         } else {
                             // Draw placeholder
@@ -424,7 +1360,7 @@ impl eframe::App for BusinessCardApp {
                 painter.text(
                     toast_rect.center(),
                     egui::Align2::CENTER_CENTER,
-                    "vCard copied to clipboard!",
+                    "Copied to clipboard!",
                     egui::FontId::proportional(14.0),
                     egui::Color32::WHITE,
                 );